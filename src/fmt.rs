@@ -16,6 +16,7 @@
 
 use crate::ast::{Expression, Quantifier};
 use crate::grapheme::GraphemeCluster;
+use crate::regexp::RegexFlavor;
 use colored::Colorize;
 use itertools::Itertools;
 use std::collections::BTreeSet;
@@ -24,28 +25,83 @@ use unic_char_range::CharRange;
 
 impl Display for Expression {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        match self {
-            Expression::Alternation(options, is_output_colorized) => {
-                format_alternation(f, &self, options, *is_output_colorized)
-            }
-            Expression::CharacterClass(char_set, is_output_colorized) => {
-                format_character_class(f, char_set, *is_output_colorized)
-            }
-            Expression::Concatenation(expr1, expr2, is_output_colorized) => {
-                format_concatenation(f, &self, expr1, expr2, *is_output_colorized)
+        Indented(self, 0).fmt(f)
+    }
+}
+
+/// Renders an expression at a given indentation depth so that nested
+/// alternation branches can lay themselves out across multiple lines in
+/// verbose mode. Depth is only ever increased by [`format_alternation`];
+/// concatenation and repetition simply forward the depth they were given.
+struct Indented<'a>(&'a Expression, usize);
+
+impl<'a> Display for Indented<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let Indented(expr, indent) = *self;
+        match expr {
+            Expression::Alternation(
+                options,
+                is_output_colorized,
+                is_verbose_mode,
+                is_capturing_group,
+            ) => format_alternation(
+                f,
+                expr,
+                options,
+                *is_output_colorized,
+                *is_verbose_mode,
+                *is_capturing_group,
+                indent,
+            ),
+            Expression::CharacterClass(
+                char_set,
+                is_output_colorized,
+                flavor,
+                is_unicode_property_matching_enabled,
+                is_verbose_mode,
+            ) => format_character_class(
+                f,
+                char_set,
+                *is_output_colorized,
+                *flavor,
+                *is_unicode_property_matching_enabled,
+                *is_verbose_mode,
+            ),
+            Expression::Concatenation(expr1, expr2, is_output_colorized, is_capturing_group) => {
+                format_concatenation(
+                    f,
+                    expr,
+                    expr1,
+                    expr2,
+                    *is_output_colorized,
+                    *is_capturing_group,
+                    indent,
+                )
             }
             Expression::Literal(
                 cluster,
                 is_non_ascii_char_escaped,
                 is_astral_code_point_converted_to_surrogate,
+                flavor,
+                is_verbose_mode,
             ) => format_literal(
                 f,
                 cluster,
                 *is_non_ascii_char_escaped,
                 *is_astral_code_point_converted_to_surrogate,
+                *flavor,
+                *is_verbose_mode,
             ),
-            Expression::Repetition(expr, quantifier, is_output_colorized) => {
-                format_repetition(f, &self, expr, quantifier, *is_output_colorized)
+            Expression::Repetition(expr1, quantifier, is_output_colorized, is_capturing_group) => {
+                format_repetition(
+                    f,
+                    expr,
+                    expr1,
+                    quantifier,
+                    *is_output_colorized,
+                    *is_capturing_group,
+                    indent,
+                )
             }
         }
     }
@@ -68,23 +124,36 @@ fn get_codepoint_position(c: char) -> usize {
     CharRange::all().iter().position(|it| it == c).unwrap()
 }
 
+const INDENTATION: &str = "  ";
+const VERBOSE_COMMENT_THRESHOLD: usize = 5;
+
+// Fixed for now rather than exposed through the builder/CLI; revisit once
+// real-world usage shows 0.9 is too strict or too lax for a given input.
+const UNICODE_PROPERTY_COVERAGE_THRESHOLD: f64 = 0.9;
+
+fn group_parentheses(is_output_colorized: bool, is_capturing_group: bool) -> (String, String) {
+    let left = if is_capturing_group { "(" } else { "(?:" };
+    if is_output_colorized {
+        (
+            left.green().bold().to_string(),
+            ")".green().bold().to_string(),
+        )
+    } else {
+        (left.to_string(), ")".to_string())
+    }
+}
+
 fn format_alternation(
     f: &mut Formatter<'_>,
     expr: &Expression,
     options: &[Expression],
     is_output_colorized: bool,
+    is_verbose_mode: bool,
+    is_capturing_group: bool,
+    indent: usize,
 ) -> Result {
-    let (left_parenthesis, right_parenthesis) = ["(", ")"]
-        .iter()
-        .map(|&it| {
-            if is_output_colorized {
-                it.green().bold()
-            } else {
-                it.clear()
-            }
-        })
-        .collect_tuple()
-        .unwrap();
+    let (left_parenthesis, right_parenthesis) =
+        group_parentheses(is_output_colorized, is_capturing_group);
 
     let pipe = if is_output_colorized {
         "|".red().bold()
@@ -92,25 +161,81 @@ fn format_alternation(
         "|".clear()
     };
 
-    let alternation_str = options
-        .iter()
-        .map(|option| {
-            if option.precedence() < expr.precedence() && !option.is_single_codepoint() {
-                format!("{}{}{}", left_parenthesis, option, right_parenthesis)
-            } else {
-                format!("{}", option)
-            }
-        })
-        .join(&pipe.to_string());
+    if is_verbose_mode {
+        let branch_indent = indent + 1;
+        let branch_indent_str = INDENTATION.repeat(branch_indent);
+        let outer_indent_str = INDENTATION.repeat(indent);
+
+        let branches = options
+            .iter()
+            .map(|option| {
+                if option.precedence() < expr.precedence() && !option.is_single_codepoint() {
+                    format!(
+                        "{}{}{}",
+                        left_parenthesis,
+                        Indented(option, branch_indent),
+                        right_parenthesis
+                    )
+                } else {
+                    format!("{}", Indented(option, branch_indent))
+                }
+            })
+            .map(|branch| format!("{}{}", branch_indent_str, branch))
+            .collect_vec();
+
+        let mut alternation_str = String::from("\n");
+        if options.len() > VERBOSE_COMMENT_THRESHOLD {
+            alternation_str.push_str(&format!("{}# {} cases\n", branch_indent_str, options.len()));
+        }
+        alternation_str.push_str(&branches.join(&format!("\n{}{} ", outer_indent_str, pipe)));
+        alternation_str.push('\n');
+        alternation_str.push_str(&outer_indent_str);
 
-    write!(f, "{}", alternation_str)
+        write!(f, "{}", alternation_str)
+    } else {
+        let alternation_str = options
+            .iter()
+            .map(|option| {
+                if option.precedence() < expr.precedence() && !option.is_single_codepoint() {
+                    format!(
+                        "{}{}{}",
+                        left_parenthesis,
+                        Indented(option, indent),
+                        right_parenthesis
+                    )
+                } else {
+                    format!("{}", Indented(option, indent))
+                }
+            })
+            .join(&pipe.to_string());
+
+        write!(f, "{}", alternation_str)
+    }
 }
 
 fn format_character_class(
     f: &mut Formatter<'_>,
     char_set: &BTreeSet<char>,
     is_output_colorized: bool,
+    flavor: RegexFlavor,
+    is_unicode_property_matching_enabled: bool,
+    is_verbose_mode: bool,
 ) -> Result {
+    if is_unicode_property_matching_enabled && flavor.supports_unicode_properties() {
+        if let Some(property) = crate::unicode_tables::find_best_matching_property(
+            char_set,
+            UNICODE_PROPERTY_COVERAGE_THRESHOLD,
+        ) {
+            let property_class = format!("\\p{{{}}}", property);
+            let property_class = if is_output_colorized {
+                property_class.cyan().bold().to_string()
+            } else {
+                property_class
+            };
+            return write!(f, "{}", property_class);
+        }
+    }
+
     let chars_to_escape = ['[', ']', '\\', '-', '^'];
     let escaped_char_set = char_set
         .iter()
@@ -123,6 +248,11 @@ fn format_character_class(
                 "\\r".to_string()
             } else if c == &'\t' {
                 "\\t".to_string()
+            } else if is_verbose_mode && (c == &' ' || c == &'#') {
+                // (?x) ignores unescaped whitespace and treats '#' as a
+                // comment marker, so both must stay escaped to keep matching
+                // the original test cases.
+                format!("{}{}", "\\", c)
             } else {
                 c.to_string()
             }
@@ -196,26 +326,24 @@ fn format_concatenation(
     expr1: &Expression,
     expr2: &Expression,
     is_output_colorized: bool,
+    is_capturing_group: bool,
+    indent: usize,
 ) -> Result {
-    let (left_parenthesis, right_parenthesis) = ["(", ")"]
-        .iter()
-        .map(|&it| {
-            if is_output_colorized {
-                it.green().bold()
-            } else {
-                it.clear()
-            }
-        })
-        .collect_tuple()
-        .unwrap();
+    let (left_parenthesis, right_parenthesis) =
+        group_parentheses(is_output_colorized, is_capturing_group);
 
     let expr_strs = vec![expr1, expr2]
         .iter()
         .map(|&it| {
             if it.precedence() < expr.precedence() && !it.is_single_codepoint() {
-                format!("{}{}{}", left_parenthesis, it, right_parenthesis)
+                format!(
+                    "{}{}{}",
+                    left_parenthesis,
+                    Indented(it, indent),
+                    right_parenthesis
+                )
             } else {
-                format!("{}", it)
+                format!("{}", Indented(it, indent))
             }
         })
         .collect_vec();
@@ -233,6 +361,8 @@ fn format_literal(
     cluster: &GraphemeCluster,
     is_non_ascii_char_escaped: bool,
     is_astral_code_point_converted_to_surrogate: bool,
+    flavor: RegexFlavor,
+    is_verbose_mode: bool,
 ) -> Result {
     let literal_str = cluster
         .graphemes()
@@ -247,12 +377,16 @@ fn format_literal(
                         repeated_grapheme.escape_regexp_symbols(
                             is_non_ascii_char_escaped,
                             is_astral_code_point_converted_to_surrogate,
+                            flavor,
+                            is_verbose_mode,
                         );
                     });
             } else {
                 grapheme.escape_regexp_symbols(
                     is_non_ascii_char_escaped,
                     is_astral_code_point_converted_to_surrogate,
+                    flavor,
+                    is_verbose_mode,
                 );
             }
             grapheme.to_string()
@@ -268,18 +402,11 @@ fn format_repetition(
     expr1: &Expression,
     quantifier: &Quantifier,
     is_output_colorized: bool,
+    is_capturing_group: bool,
+    indent: usize,
 ) -> Result {
-    let (left_parenthesis, right_parenthesis) = ["(", ")"]
-        .iter()
-        .map(|&it| {
-            if is_output_colorized {
-                it.green().bold()
-            } else {
-                it.clear()
-            }
-        })
-        .collect_tuple()
-        .unwrap();
+    let (left_parenthesis, right_parenthesis) =
+        group_parentheses(is_output_colorized, is_capturing_group);
 
     let colored_quantifier = if is_output_colorized {
         quantifier.to_string().as_str().purple().bold()
@@ -291,9 +418,12 @@ fn format_repetition(
         write!(
             f,
             "{}{}{}{}",
-            left_parenthesis, expr1, right_parenthesis, colored_quantifier
+            left_parenthesis,
+            Indented(expr1, indent),
+            right_parenthesis,
+            colored_quantifier
         )
     } else {
-        write!(f, "{}{}", expr1, colored_quantifier)
+        write!(f, "{}{}", Indented(expr1, indent), colored_quantifier)
     }
 }
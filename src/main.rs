@@ -14,7 +14,7 @@
  * limitations under the License.
  */
 
-use grex::{Feature, RegExpBuilder};
+use grex::{Feature, RegExpBuilder, RegexFlavor, Segmenter};
 use itertools::Itertools;
 use std::io::{Error, ErrorKind};
 use std::path::PathBuf;
@@ -162,6 +162,98 @@ struct CLI {
         display_order = 10
     )]
     is_output_colorized: bool,
+
+    #[structopt(
+        name = "ignore-case",
+        short,
+        long,
+        help = "Performs case-insensitive matching, letting characters of different \
+                case match each other",
+        long_help = "Performs case-insensitive matching, letting characters of different \
+                     case match each other.\n\n\
+                     Characters are folded to a single case wherever possible and the \
+                     resulting pattern is prefixed with the inline modifier (?i).",
+        display_order = 11
+    )]
+    is_case_insensitive_matching: bool,
+
+    #[structopt(
+        name = "flavor",
+        long,
+        value_name = "FLAVOR",
+        help = "Specifies the regex flavor for which the output should be optimized",
+        long_help = "Specifies the regex flavor for which the output should be optimized.\n\n\
+                     Supported flavors are: rust, javascript, python, dotnet, java, pcre.\n\
+                     Defaults to rust if not specified.",
+        default_value = "rust",
+        display_order = 12
+    )]
+    flavor: RegexFlavor,
+
+    #[structopt(
+        name = "verbose",
+        short = "x",
+        long,
+        help = "Produces a verbose regular expression with additional whitespace and comments",
+        long_help = "Produces a verbose regular expression with additional whitespace and \
+                     comments.\n\n\
+                     The resulting pattern is prefixed with the inline modifier (?x), each \
+                     alternation branch is indented on its own line, and large alternations \
+                     are preceded by a comment stating how many cases they cover.",
+        display_order = 13
+    )]
+    is_output_verbose: bool,
+
+    #[structopt(
+        name = "properties",
+        short,
+        long,
+        help = "Replaces character classes by their corresponding Unicode properties, \
+                if possible",
+        long_help = "Replaces character classes by their corresponding Unicode properties, \
+                     if possible.\n\n\
+                     A character class is only replaced with a Unicode general category or \
+                     script (e.g. \\p{Lu}, \\p{Greek}) if that property covers a high enough \
+                     fraction of the class, so that the substitution does not end up matching \
+                     considerably more than the original test cases did.",
+        display_order = 14
+    )]
+    is_unicode_property_matching_enabled: bool,
+
+    #[structopt(
+        name = "segment",
+        long,
+        value_name = "GRANULARITY",
+        help = "Specifies the granularity at which test cases are split into units before \
+                being compared",
+        long_help = "Specifies the granularity at which test cases are split into units \
+                     before being compared.\n\n\
+                     Supported granularities are: grapheme, word.\n\n\
+                     Segmentation is locale-aware, so multi-code-point user-perceived \
+                     characters such as emoji ZWJ sequences and flags are treated as a \
+                     single grapheme instead of being torn apart.\n\n\
+                     Defaults to grapheme if not specified.",
+        default_value = "grapheme",
+        display_order = 15
+    )]
+    segmentation: Segmenter,
+
+    #[structopt(
+        name = "anchors",
+        long,
+        help = "Wraps the resulting regular expression in ^(?:...)$ so that it only matches \
+                when the entire input matches",
+        display_order = 16
+    )]
+    is_output_anchored: bool,
+
+    #[structopt(
+        name = "no-capturing-groups",
+        long,
+        help = "Uses non-capturing groups (?:...) instead of capturing ones (...)",
+        display_order = 17
+    )]
+    is_capturing_group_disabled: bool,
 }
 
 fn main() {
@@ -188,6 +280,15 @@ fn obtain_input(cli: &CLI) -> Result<Vec<String>, Error> {
 fn handle_input(cli: &CLI, input: Result<Vec<String>, Error>) {
     match input {
         Ok(test_cases) => {
+            if cli.is_output_verbose && !cli.flavor.supports_verbose_mode() {
+                eprintln!(
+                    "error: --verbose cannot be combined with --flavor {:?} \
+                     because this flavor has no extended/verbose mode",
+                    cli.flavor
+                );
+                return;
+            }
+
             let mut builder = RegExpBuilder::from(&test_cases);
             let mut conversion_features = vec![];
 
@@ -219,6 +320,10 @@ fn handle_input(cli: &CLI, input: Result<Vec<String>, Error>) {
                 conversion_features.push(Feature::Repetition);
             }
 
+            if cli.is_unicode_property_matching_enabled {
+                conversion_features.push(Feature::UnicodeProperty);
+            }
+
             if !conversion_features.is_empty() {
                 builder.with_conversion_of(&conversion_features);
             }
@@ -229,6 +334,26 @@ fn handle_input(cli: &CLI, input: Result<Vec<String>, Error>) {
                 );
             }
 
+            if cli.is_case_insensitive_matching {
+                builder.with_case_insensitive_matching();
+            }
+
+            builder.with_flavor(cli.flavor);
+
+            if cli.is_output_verbose {
+                builder.with_verbose_mode();
+            }
+
+            builder.with_segmentation(cli.segmentation);
+
+            if cli.is_output_anchored {
+                builder.with_anchors();
+            }
+
+            if cli.is_capturing_group_disabled {
+                builder.without_capturing_groups();
+            }
+
             if cli.is_output_colorized {
                 builder.with_syntax_highlighting();
             }
@@ -0,0 +1,54 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use grex::RegExpBuilder;
+use regex::Regex;
+
+#[test]
+fn non_capturing_groups_replace_capturing_ones() {
+    let input = vec!["foobar", "foobaz", "car"];
+
+    let capturing_pattern = RegExpBuilder::from(&input).build();
+    let non_capturing_pattern = RegExpBuilder::from(&input)
+        .without_capturing_groups()
+        .build();
+
+    assert!(capturing_pattern.contains("(bar|baz)"));
+    assert!(!capturing_pattern.contains("(?:bar|baz)"));
+    assert!(non_capturing_pattern.contains("(?:bar|baz)"));
+
+    let regex = Regex::new(&non_capturing_pattern).unwrap();
+    for test_case in &input {
+        assert!(regex.is_match(test_case));
+    }
+}
+
+#[test]
+fn anchors_wrap_the_pattern_exactly_once() {
+    let input = vec!["abc", "abd"];
+    let pattern = RegExpBuilder::from(&input).with_anchors().build();
+
+    assert!(pattern.starts_with("^(?:"));
+    assert!(pattern.ends_with(")$"));
+    assert_eq!(pattern.matches('^').count(), 1);
+    assert_eq!(pattern.matches('$').count(), 1);
+
+    let regex = Regex::new(&pattern).unwrap();
+    for test_case in &input {
+        assert!(regex.is_match(test_case));
+    }
+    assert!(!regex.is_match("xabcx"));
+}
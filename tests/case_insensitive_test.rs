@@ -0,0 +1,57 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use grex::RegExpBuilder;
+use regex::Regex;
+
+#[test]
+fn folds_ascii_case_and_prefixes_the_inline_modifier() {
+    let input = vec!["Hello", "hello"];
+    let pattern = RegExpBuilder::from(&input)
+        .with_case_insensitive_matching()
+        .build();
+
+    assert_eq!(pattern, "(?i)hello");
+
+    let regex = Regex::new(&pattern).unwrap();
+    for test_case in &input {
+        assert!(regex.is_match(test_case));
+    }
+}
+
+#[test]
+fn keeps_non_1_to_1_case_foldings_uncollapsed() {
+    // ß folds to "ss", a 2-codepoint expansion, so the original grapheme
+    // must be kept verbatim rather than being corrupted into a class.
+    let input = vec!["stra\u{00df}e", "STRASSE"];
+    let pattern = RegExpBuilder::from(&input)
+        .with_case_insensitive_matching()
+        .build();
+
+    assert!(pattern.contains('\u{00df}'));
+}
+
+#[test]
+fn keeps_turkish_dotless_i_uncollapsed() {
+    // Turkish dotless ı (U+0131) does not case-fold 1:1 with ASCII 'i'/'I',
+    // so it must survive folding unchanged instead of being merged away.
+    let input = vec!["KURU\u{0131}", "kuru\u{0131}"];
+    let pattern = RegExpBuilder::from(&input)
+        .with_case_insensitive_matching()
+        .build();
+
+    assert!(pattern.contains('\u{0131}'));
+}
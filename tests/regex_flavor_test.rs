@@ -0,0 +1,60 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use grex::{RegExpBuilder, RegexFlavor};
+
+#[test]
+fn rust_flavor_keeps_the_default_brace_escape() {
+    let input = vec!["\u{1f600}"];
+    let pattern = RegExpBuilder::from(&input)
+        .with_escaping_of_non_ascii_chars(false)
+        .build();
+
+    assert_eq!(pattern, "\\u{1F600}");
+}
+
+#[test]
+fn javascript_flavor_emits_uxxxx_escapes_with_surrogate_pairs() {
+    let input = vec!["\u{1f600}"];
+    let pattern = RegExpBuilder::from(&input)
+        .with_flavor(RegexFlavor::JavaScript)
+        .with_escaping_of_non_ascii_chars(false)
+        .build();
+
+    assert_eq!(pattern, "\\ud83d\\ude00");
+}
+
+#[test]
+fn python_flavor_emits_8_digit_escapes() {
+    let input = vec!["\u{1f600}"];
+    let pattern = RegExpBuilder::from(&input)
+        .with_flavor(RegexFlavor::Python)
+        .with_escaping_of_non_ascii_chars(false)
+        .build();
+
+    assert_eq!(pattern, "\\U0001F600");
+}
+
+#[test]
+fn pcre_flavor_emits_braced_hex_escapes() {
+    let input = vec!["\u{1f600}"];
+    let pattern = RegExpBuilder::from(&input)
+        .with_flavor(RegexFlavor::Pcre)
+        .with_escaping_of_non_ascii_chars(false)
+        .build();
+
+    assert_eq!(pattern, "\\x{1F600}");
+}
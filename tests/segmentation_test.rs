@@ -0,0 +1,57 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use grex::{RegExpBuilder, Segmenter};
+use regex::Regex;
+
+#[test]
+fn treats_a_zwj_emoji_family_sequence_as_a_single_grapheme() {
+    // 👨‍👩‍👧 - man, ZWJ, woman, ZWJ, girl
+    let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+    let input = vec![family];
+    let pattern = RegExpBuilder::from(&input)
+        .with_segmentation(Segmenter::Grapheme)
+        .build();
+
+    let regex = Regex::new(&pattern).unwrap();
+    assert!(regex.is_match(family));
+}
+
+#[test]
+fn treats_a_flag_regional_indicator_pair_as_a_single_grapheme() {
+    // 🇩🇪 - regional indicators D and E
+    let flag = "\u{1f1e9}\u{1f1ea}";
+    let input = vec![flag];
+    let pattern = RegExpBuilder::from(&input)
+        .with_segmentation(Segmenter::Grapheme)
+        .build();
+
+    let regex = Regex::new(&pattern).unwrap();
+    assert!(regex.is_match(flag));
+}
+
+#[test]
+fn word_segmentation_matches_shared_whole_words() {
+    let input = vec!["hello world", "hello there"];
+    let pattern = RegExpBuilder::from(&input)
+        .with_segmentation(Segmenter::Word)
+        .build();
+
+    let regex = Regex::new(&pattern).unwrap();
+    for test_case in &input {
+        assert!(regex.is_match(test_case));
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use grex::{Feature, RegExpBuilder};
+use regex::Regex;
+
+#[test]
+fn emits_unicode_property_when_coverage_is_high_enough() {
+    let input: Vec<String> = ('A'..='Z').map(|c| c.to_string()).collect();
+    let pattern = RegExpBuilder::from(&input)
+        .with_conversion_of(&[Feature::UnicodeProperty])
+        .build();
+
+    assert!(
+        pattern.contains("\\p{Lu}") || pattern.contains("\\p{L}"),
+        "expected a \\p{{...}} substitution in: {}",
+        pattern
+    );
+
+    let regex = Regex::new(&pattern).unwrap();
+    for test_case in &input {
+        assert!(regex.is_match(test_case));
+    }
+}
+
+#[test]
+fn falls_back_to_explicit_ranges_below_the_coverage_threshold() {
+    // Mixing digits into an otherwise all-uppercase-letter class should
+    // push it below the configured coverage threshold for \p{Lu}, so the
+    // substitution must not happen and the explicit range is kept instead.
+    let mut input: Vec<String> = ('A'..='Z').map(|c| c.to_string()).collect();
+    input.push("1".to_string());
+    input.push("2".to_string());
+    input.push("3".to_string());
+
+    let pattern = RegExpBuilder::from(&input)
+        .with_conversion_of(&[Feature::UnicodeProperty])
+        .build();
+
+    assert!(
+        !pattern.contains("\\p{"),
+        "did not expect a \\p{{...}} substitution in: {}",
+        pattern
+    );
+
+    let regex = Regex::new(&pattern).unwrap();
+    for test_case in &input {
+        assert!(regex.is_match(test_case));
+    }
+}
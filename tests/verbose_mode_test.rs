@@ -0,0 +1,93 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use grex::RegExpBuilder;
+use regex::Regex;
+
+#[test]
+fn escapes_whitespace_in_verbose_literals_and_character_classes() {
+    let input = vec!["a b c", "a bxc"];
+    let pattern = RegExpBuilder::from(&input).with_verbose_mode().build();
+
+    let body = pattern.strip_prefix("(?x)").unwrap_or(&pattern);
+    let without_escaped_spaces = body.replace("\\ ", "");
+    assert!(
+        !without_escaped_spaces.contains(' '),
+        "every space in a verbose pattern must be escaped: {}",
+        pattern
+    );
+
+    let regex = Regex::new(&pattern).unwrap();
+    for test_case in &input {
+        assert!(regex.is_match(test_case));
+    }
+}
+
+#[test]
+fn verbose_output_matches_the_same_input_as_the_compact_output() {
+    let input = vec!["a b", "a c", "a d", "a e", "a f", "a g"];
+
+    let compact_pattern = RegExpBuilder::from(&input).build();
+    let verbose_pattern = RegExpBuilder::from(&input).with_verbose_mode().build();
+
+    assert!(verbose_pattern.starts_with("(?x)"));
+
+    let compact_regex = Regex::new(&compact_pattern).unwrap();
+    let verbose_regex = Regex::new(&verbose_pattern).unwrap();
+
+    for test_case in &input {
+        assert!(compact_regex.is_match(test_case));
+        assert!(verbose_regex.is_match(test_case));
+    }
+}
+
+#[test]
+fn verbose_alternation_lays_out_branches_on_separate_indented_lines_with_a_case_comment() {
+    // None of these collapse into a single character class, so the
+    // alternation keeps all 6 branches, crossing VERBOSE_COMMENT_THRESHOLD.
+    let input = vec!["apple", "banana", "cherry", "date", "elderberry", "fig"];
+    let pattern = RegExpBuilder::from(&input).with_verbose_mode().build();
+
+    assert!(pattern.starts_with("(?x)"));
+    assert!(
+        pattern.contains("# 6 cases"),
+        "expected a case count comment in: {}",
+        pattern
+    );
+
+    let lines: Vec<&str> = pattern.lines().collect();
+    assert!(
+        lines.len() > 1,
+        "expected the alternation to span multiple lines: {}",
+        pattern
+    );
+    for test_case in &input {
+        assert!(
+            lines.iter().any(|line| {
+                line.trim_start_matches(|c: char| c == '|' || c.is_whitespace()) == *test_case
+            }),
+            "expected a line containing just {:?} in: {}",
+            test_case,
+            pattern
+        );
+    }
+    assert!(lines.iter().any(|line| line.trim_start().starts_with('|')));
+
+    let regex = Regex::new(&pattern).unwrap();
+    for test_case in &input {
+        assert!(regex.is_match(test_case));
+    }
+}